@@ -31,4 +31,31 @@ pub enum Error {
     /// Problem with UTF-8 conversion.
     #[error("problem with UTF-8 conversion: {0}")]
     InvalidUtf8(#[source] FromUtf8Error),
+    /// Problem listing the live `SST` files of a `RocksDB`.
+    #[error("problem listing RocksDB live files: {0}")]
+    LiveFiles(#[source] rocksdb::Error),
+    /// Problem creating a `RocksDB` checkpoint.
+    #[error("problem creating RocksDB checkpoint: {0}")]
+    Checkpoint(#[source] rocksdb::Error),
+    /// Problem deleting a range of `SST` files from a `RocksDB` column family.
+    #[error("problem deleting RocksDB file range: {0}")]
+    DeleteRange(#[source] rocksdb::Error),
+    /// Problem querying `RocksDB` memory usage.
+    #[error("problem querying RocksDB memory usage: {0}")]
+    MemoryUsage(#[source] rocksdb::Error),
+    /// Problem writing data.
+    #[error("problem writing data to RocksDB: {0}")]
+    WriteData(#[source] rocksdb::Error),
+    /// Problem parsing a meta value into the requested type.
+    #[error("problem parsing meta value for key {0}: {1}")]
+    MetaParse(String, String),
+    /// Problem writing an `SST` file via `SstFileWriter`.
+    #[error("problem writing SST file: {0}")]
+    SstWrite(#[source] rocksdb::Error),
+    /// Keys were not put into an `SstBulkLoader` in strictly increasing order.
+    #[error("keys must be inserted in strictly increasing order, got {0:?} after {1:?}")]
+    SstOutOfOrder(Vec<u8>, Vec<u8>),
+    /// Problem ingesting external `SST` files into a `RocksDB`.
+    #[error("problem ingesting external SST files: {0}")]
+    Ingest(#[source] rocksdb::Error),
 }
@@ -1,11 +1,113 @@
 //! The implementation of the library.
 
-use std::{path::Path, time::Instant};
+use std::{collections::BTreeMap, path::Path, str::FromStr, time::Instant};
+
+use rocksdb::checkpoint::Checkpoint;
 
 use crate::error;
 
+/// Block index format to use for a `TuningProfile`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum IndexType {
+    /// Two-level partitioned index, recommended for large databases with point lookups.
+    TwoLevel,
+    /// Classic single-level binary-search index.
+    BinarySearch,
+}
+
+impl From<IndexType> for rocksdb::BlockBasedIndexType {
+    fn from(value: IndexType) -> Self {
+        match value {
+            IndexType::TwoLevel => rocksdb::BlockBasedIndexType::TwoLevelIndexSearch,
+            IndexType::BinarySearch => rocksdb::BlockBasedIndexType::BinarySearch,
+        }
+    }
+}
+
+/// Tuning parameters for [`tune_options`].
+///
+/// `Default` reproduces the hardcoded values that `tune_options` used before this struct
+/// existed.  Use [`TuningProfile::bulk_load`] or [`TuningProfile::point_lookup`] for presets
+/// geared towards the two most common use cases of this crate, or construct one and override
+/// individual fields to fit your database size and machine.
+#[derive(Debug, Clone)]
+pub struct TuningProfile {
+    /// Level of parallelism to use (passed to `increase_parallelism`), roughly the number of
+    /// cores to use for flushes and compactions.
+    pub parallelism: i32,
+    /// Maximum number of concurrent background compaction/flush jobs.
+    pub max_background_jobs: i32,
+    /// Maximum number of concurrent subcompactions.
+    pub max_subcompactions: u32,
+    /// Size in bytes of each memtable.
+    pub write_buffer_size: usize,
+    /// Target size in bytes of each `SST` file.
+    pub target_file_size_base: u64,
+    /// Compaction style to use.
+    pub compaction_style: rocksdb::DBCompactionStyle,
+    /// Compression algorithm to use for the bottommost level.
+    pub compression_type: rocksdb::DBCompressionType,
+    /// Maximum number of bytes sampled for training the bottommost zstd dictionary.
+    pub bottommost_zstd_max_train_bytes: u32,
+    /// Size in bytes of the block cache used for point lookups.
+    pub block_cache_size: usize,
+    /// Bits per key to use for the bloom filter.
+    pub bloom_bits_per_key: f64,
+    /// Block index type to use.
+    pub index_type: IndexType,
+    /// Whether to cache index and filter blocks with high cache priority.
+    pub cache_index_and_filter_blocks_with_high_priority: bool,
+}
+
+impl Default for TuningProfile {
+    fn default() -> Self {
+        Self {
+            parallelism: 8,
+            max_background_jobs: 16,
+            max_subcompactions: 8,
+            write_buffer_size: 1 << 30,
+            target_file_size_base: 1 << 30,
+            compaction_style: rocksdb::DBCompactionStyle::Universal,
+            compression_type: rocksdb::DBCompressionType::Zstd,
+            bottommost_zstd_max_train_bytes: 1 << 22,
+            block_cache_size: 1 << 26,
+            bloom_bits_per_key: 10.0,
+            index_type: IndexType::TwoLevel,
+            cache_index_and_filter_blocks_with_high_priority: true,
+        }
+    }
+}
+
+impl TuningProfile {
+    /// Preset geared towards bulk loading of large, write-once lookup databases.
+    ///
+    /// This is the same as [`TuningProfile::default`], which was tuned with bulk loading in
+    /// mind from the start.
+    #[must_use]
+    pub fn bulk_load() -> Self {
+        Self::default()
+    }
+
+    /// Preset geared towards databases that are optimized for point lookups after loading,
+    /// trading a larger block cache for fewer background jobs.
+    #[must_use]
+    pub fn point_lookup() -> Self {
+        Self {
+            max_background_jobs: 4,
+            max_subcompactions: 2,
+            parallelism: 2,
+            block_cache_size: 1 << 30,
+            cache_index_and_filter_blocks_with_high_priority: true,
+            ..Self::default()
+        }
+    }
+}
+
 /// Tune `RocksDB` options for bulk insertion.
 ///
+/// This is a thin wrapper around [`tune_options_with_profile`] using [`TuningProfile::default`],
+/// which reproduces the values this function used before tuning was made configurable.
+///
 /// # Arguments
 ///
 /// * `options` - `RocksDB` options to tune.
@@ -15,6 +117,25 @@ use crate::error;
 ///
 /// Tuned `RocksDB` options.
 pub fn tune_options(options: rocksdb::Options, wal_dir: Option<&str>) -> rocksdb::Options {
+    tune_options_with_profile(options, wal_dir, &TuningProfile::default())
+}
+
+/// Tune `RocksDB` options for bulk insertion using the given `TuningProfile`.
+///
+/// # Arguments
+///
+/// * `options` - `RocksDB` options to tune.
+/// * `wal_dir` - Optional directory for write-ahead log files.
+/// * `profile` - Tuning parameters to apply.
+///
+/// # Returns
+///
+/// Tuned `RocksDB` options.
+pub fn tune_options_with_profile(
+    options: rocksdb::Options,
+    wal_dir: Option<&str>,
+    profile: &TuningProfile,
+) -> rocksdb::Options {
     let mut options = options;
 
     options.create_if_missing(true);
@@ -22,15 +143,15 @@ pub fn tune_options(options: rocksdb::Options, wal_dir: Option<&str>) -> rocksdb
 
     options.prepare_for_bulk_load();
 
-    options.set_max_background_jobs(16);
-    options.set_max_subcompactions(8);
-    options.increase_parallelism(8);
-    options.optimize_level_style_compaction(1 << 30);
+    options.set_max_background_jobs(profile.max_background_jobs);
+    options.set_max_subcompactions(profile.max_subcompactions);
+    options.increase_parallelism(profile.parallelism);
+    options.optimize_level_style_compaction(profile.write_buffer_size as u64);
     options.set_min_write_buffer_number(1);
     options.set_min_write_buffer_number_to_merge(1);
-    options.set_write_buffer_size(1 << 30);
-    options.set_target_file_size_base(1 << 30);
-    options.set_compaction_style(rocksdb::DBCompactionStyle::Universal);
+    options.set_write_buffer_size(profile.write_buffer_size);
+    options.set_target_file_size_base(profile.target_file_size_base);
+    options.set_compaction_style(profile.compaction_style);
 
     if let Some(wal_dir) = wal_dir {
         options.set_wal_dir(wal_dir);
@@ -39,24 +160,26 @@ pub fn tune_options(options: rocksdb::Options, wal_dir: Option<&str>) -> rocksdb
     // Compress everything with zstd.
     options.set_compression_per_level(&[]);
     options.set_bottommost_compression_options(-14, 10, 0, 1 << 14, true);
-    options.set_bottommost_compression_type(rocksdb::DBCompressionType::Zstd);
-    options.set_bottommost_zstd_max_train_bytes(1 << 22, true);
-    options.optimize_for_point_lookup(1 << 26);
+    options.set_bottommost_compression_type(profile.compression_type);
+    options.set_bottommost_zstd_max_train_bytes(profile.bottommost_zstd_max_train_bytes, true);
+    options.optimize_for_point_lookup(profile.block_cache_size as u64);
 
     // Setup partitioned index filters
     let mut block_opts = rocksdb::BlockBasedOptions::default();
-    block_opts.set_index_type(rocksdb::BlockBasedIndexType::TwoLevelIndexSearch);
-    // 10 bits per key are a reasonbel default
+    block_opts.set_index_type(profile.index_type.into());
+    // Bits per key for the bloom filter, see:
     //
     // https://github.com/facebook/rocksdb/wiki/RocksDB-Bloom-Filter
     // https://www.percona.com/blog/how-bloom-filters-work-in-myrocks/
-    block_opts.set_bloom_filter(10.0, false);
+    block_opts.set_bloom_filter(profile.bloom_bits_per_key, false);
     block_opts.set_partition_filters(true);
     block_opts.set_metadata_block_size(4096);
     block_opts.set_cache_index_and_filter_blocks(true);
     block_opts.set_pin_top_level_index_and_filter(true);
     block_opts.set_pin_l0_filter_and_index_blocks_in_cache(true);
-    // MISSING: cache_index_and_filter_blocks_with_high_priority
+    block_opts.set_cache_index_and_filter_blocks_with_high_priority(
+        profile.cache_index_and_filter_blocks_with_high_priority,
+    );
     options.set_block_based_table_factory(&block_opts);
 
     options
@@ -199,6 +322,158 @@ where
     Ok(())
 }
 
+/// Metadata about a single on-disk `SST` file, as reported by [`list_sst_files`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SstFileMeta {
+    /// Name of the `SST` file.
+    pub name: String,
+    /// Size of the `SST` file in bytes.
+    pub size: u64,
+    /// Level of the `RocksDB` LSM tree the file resides in.
+    pub level: i32,
+    /// Smallest user key contained in the file, if any.
+    pub start_key: Option<Vec<u8>>,
+    /// Largest user key contained in the file, if any.
+    pub end_key: Option<Vec<u8>>,
+}
+
+/// List the live `SST` files of a `RocksDB`, reporting their level and key range.
+///
+/// # Arguments
+///
+/// * `db` - `RocksDB` database to list the live files of.
+///
+/// # Errors
+///
+/// Returns an error in the case the underlying `RocksDB` operation fails.
+pub fn list_sst_files(
+    db: &rocksdb::DBWithThreadMode<rocksdb::MultiThreaded>,
+) -> Result<Vec<SstFileMeta>, error::Error> {
+    let live_files = db.live_files().map_err(error::Error::LiveFiles)?;
+    Ok(live_files
+        .into_iter()
+        .map(|live_file| SstFileMeta {
+            name: live_file.name,
+            size: live_file.size as u64,
+            level: live_file.level,
+            start_key: live_file.start_key,
+            end_key: live_file.end_key,
+        })
+        .collect())
+}
+
+/// Create a consistent, point-in-time checkpoint of a `RocksDB` at `target_path`.
+///
+/// The checkpoint is hard-linked to the source database where possible, falling back to
+/// copies across filesystems.
+///
+/// # Arguments
+///
+/// * `db` - `RocksDB` database to create a checkpoint of.
+/// * `target_path` - Path to create the checkpoint at.  Must not already exist.
+///
+/// # Errors
+///
+/// Returns an error in the case the underlying `RocksDB` operation fails.
+pub fn create_checkpoint<P>(
+    db: &rocksdb::DBWithThreadMode<rocksdb::MultiThreaded>,
+    target_path: P,
+) -> Result<(), error::Error>
+where
+    P: AsRef<Path>,
+{
+    let checkpoint = Checkpoint::new(db).map_err(error::Error::Checkpoint)?;
+    checkpoint
+        .create_checkpoint(target_path.as_ref())
+        .map_err(error::Error::Checkpoint)
+}
+
+/// Delete the user-key range `[start, end)` of a column family, dropping whole `SST` files that
+/// fall fully inside the range first as a fast path, optionally followed by a compaction over
+/// that range.
+///
+/// `delete_file_in_range_cf` alone removes entire `SST` files without rewriting data, which is
+/// far cheaper than deleting a key range one key at a time, but it leaves keys in files that
+/// only partially overlap `[start, end)` untouched.  To actually guarantee the range is gone,
+/// this function always follows up with a `delete_range_cf` tombstone over `[start, end)`, which
+/// is what makes the deletion correct for the partial-overlap case.
+///
+/// Pass `compact_afterwards = true` to additionally run a `compact_range_cf` over `[start, end)`,
+/// which physically purges the tombstoned and superseded keys and reclaims their disk space
+/// instead of leaving them to be dropped by a later background compaction.
+///
+/// # Arguments
+///
+/// * `db` - `RocksDB` database to delete the range from.
+/// * `cf_name` - Name of the column family to delete the range from.
+/// * `start` - Inclusive start of the user-key range.
+/// * `end` - Exclusive end of the user-key range.
+/// * `compact_afterwards` - Whether to run a `compact_range_cf` over `[start, end)` afterwards to
+///   reclaim disk space immediately.
+///
+/// # Errors
+///
+/// Returns an error in the case the underlying `RocksDB` operation fails or the column family
+/// does not exist.
+pub fn delete_range_fast(
+    db: &rocksdb::DBWithThreadMode<rocksdb::MultiThreaded>,
+    cf_name: &str,
+    start: &[u8],
+    end: &[u8],
+    compact_afterwards: bool,
+) -> Result<(), error::Error> {
+    let cf = db
+        .cf_handle(cf_name)
+        .ok_or_else(|| error::Error::ColumnFamily(cf_name.to_owned()))?;
+
+    db.delete_file_in_range_cf(&cf, start, end)
+        .map_err(error::Error::DeleteRange)?;
+    db.delete_range_cf(&cf, start, end)
+        .map_err(error::Error::DeleteRange)?;
+
+    if compact_afterwards {
+        db.compact_range_cf(&cf, Some(start), Some(end));
+    }
+
+    Ok(())
+}
+
+/// Aggregated `RocksDB` memory usage, as reported by [`memory_usage`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MemoryUsageStats {
+    /// Total memory used by all memtables, in bytes.
+    pub mem_table_total: u64,
+    /// Memory used by memtables that have not yet been flushed, in bytes.
+    pub mem_table_unflushed: u64,
+    /// Memory used by table readers (e.g. pinned index and filter blocks), in bytes.
+    pub mem_table_readers_total: u64,
+    /// Total memory used by block caches, in bytes.
+    pub cache_total: u64,
+}
+
+/// Report the aggregated memory usage of a `RocksDB`.
+///
+/// # Arguments
+///
+/// * `db` - `RocksDB` database to report memory usage for.
+///
+/// # Errors
+///
+/// Returns an error in the case the underlying `RocksDB` operation fails.
+pub fn memory_usage(
+    db: &rocksdb::DBWithThreadMode<rocksdb::MultiThreaded>,
+) -> Result<MemoryUsageStats, error::Error> {
+    let usage = rocksdb::perf::get_memory_usage_stats(Some(&[db]), None)
+        .map_err(error::Error::MemoryUsage)?;
+
+    Ok(MemoryUsageStats {
+        mem_table_total: usage.mem_table_total,
+        mem_table_unflushed: usage.mem_table_unflushed,
+        mem_table_readers_total: usage.mem_table_readers_total,
+        cache_total: usage.cache_total,
+    })
+}
+
 /// Function to fetch a meta value as a string from a `RocksDB`.
 ///
 /// # Errors
@@ -219,6 +494,181 @@ pub fn fetch_meta(
         .transpose()
 }
 
+/// Function to fetch a meta value from the `meta` column family and parse it into `T`.
+///
+/// # Errors
+///
+/// Returns an error in the case of problems with the `RocksDB` access or if the value cannot be
+/// parsed into `T`.
+pub fn fetch_meta_typed<T>(
+    db: &rocksdb::DBWithThreadMode<rocksdb::MultiThreaded>,
+    key: &str,
+) -> Result<Option<T>, error::Error>
+where
+    T: FromStr,
+    T::Err: std::fmt::Display,
+{
+    fetch_meta(db, key)?
+        .map(|raw_value| {
+            raw_value
+                .parse()
+                .map_err(|e: T::Err| error::Error::MetaParse(key.to_owned(), e.to_string()))
+        })
+        .transpose()
+}
+
+/// Function to fetch all key/value pairs from the `meta` column family.
+///
+/// # Errors
+///
+/// Returns an error in the case of problems with the `RocksDB` access.
+pub fn fetch_all_meta(
+    db: &rocksdb::DBWithThreadMode<rocksdb::MultiThreaded>,
+) -> Result<BTreeMap<String, String>, error::Error> {
+    let cf_meta = db
+        .cf_handle("meta")
+        .ok_or(error::Error::UnknownColumnFamily)?;
+
+    db.iterator_cf(&cf_meta, rocksdb::IteratorMode::Start)
+        .map(|entry| {
+            let (key, value) = entry.map_err(error::Error::ReadData)?;
+            let key = String::from_utf8(key.into_vec()).map_err(error::Error::InvalidUtf8)?;
+            let value =
+                String::from_utf8(value.into_vec()).map_err(error::Error::InvalidUtf8)?;
+            Ok((key, value))
+        })
+        .collect()
+}
+
+/// Function to write a single meta value as a string into the `meta` column family.
+///
+/// # Errors
+///
+/// Returns an error in the case of problems with the `RocksDB` access.
+pub fn put_meta(
+    db: &rocksdb::DBWithThreadMode<rocksdb::MultiThreaded>,
+    key: &str,
+    value: &str,
+) -> Result<(), error::Error> {
+    put_meta_batch(db, &[(key, value)])
+}
+
+/// Function to atomically write multiple meta key/value pairs into the `meta` column family.
+///
+/// # Errors
+///
+/// Returns an error in the case of problems with the `RocksDB` access.
+pub fn put_meta_batch(
+    db: &rocksdb::DBWithThreadMode<rocksdb::MultiThreaded>,
+    entries: &[(&str, &str)],
+) -> Result<(), error::Error> {
+    let cf_meta = db
+        .cf_handle("meta")
+        .ok_or(error::Error::UnknownColumnFamily)?;
+
+    let mut batch = rocksdb::WriteBatch::default();
+    for (key, value) in entries {
+        batch.put_cf(&cf_meta, key.as_bytes(), value.as_bytes());
+    }
+    db.write(batch).map_err(error::Error::WriteData)
+}
+
+/// Builder for a single `SST` file, for ingestion with [`ingest`].
+///
+/// Keys must be put in strictly increasing order, as required by the `SST` file format.
+pub struct SstBulkLoader<'a> {
+    writer: rocksdb::SstFileWriter<'a>,
+    path: std::path::PathBuf,
+    last_key: Option<Vec<u8>>,
+}
+
+impl<'a> SstBulkLoader<'a> {
+    /// Create a new `SstBulkLoader` writing a single `SST` file into `out_dir`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error in the case the underlying `RocksDB` operation fails.
+    pub fn new<P>(options: &'a rocksdb::Options, out_dir: P) -> Result<Self, error::Error>
+    where
+        P: AsRef<Path>,
+    {
+        let path = out_dir.as_ref().join("bulk-load.sst");
+        let writer = rocksdb::SstFileWriter::create(options);
+        writer.open(&path).map_err(error::Error::SstWrite)?;
+
+        Ok(Self {
+            writer,
+            path,
+            last_key: None,
+        })
+    }
+
+    /// Put a key/value pair into the `SST` file.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error in the case the underlying `RocksDB` operation fails or `key` is not
+    /// strictly greater than the previously put key.
+    pub fn put(&mut self, key: &[u8], value: &[u8]) -> Result<(), error::Error> {
+        if let Some(last_key) = &self.last_key {
+            if key <= last_key.as_slice() {
+                return Err(error::Error::SstOutOfOrder(
+                    key.to_vec(),
+                    last_key.clone(),
+                ));
+            }
+        }
+
+        self.writer.put(key, value).map_err(error::Error::SstWrite)?;
+        self.last_key = Some(key.to_vec());
+
+        Ok(())
+    }
+
+    /// Flush the `SST` file to disk and return its path.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error in the case the underlying `RocksDB` operation fails.
+    pub fn finish(mut self) -> Result<std::path::PathBuf, error::Error> {
+        self.writer.finish().map_err(error::Error::SstWrite)?;
+        Ok(self.path)
+    }
+}
+
+/// Ingest previously built external `SST` files into a `RocksDB` column family.
+///
+/// # Arguments
+///
+/// * `db` - `RocksDB` database to ingest the files into.
+/// * `cf_name` - Name of the column family to ingest the files into.
+/// * `files` - Paths of the `SST` files to ingest, as produced by [`SstBulkLoader::finish`].
+///
+/// # Errors
+///
+/// Returns an error in the case the underlying `RocksDB` operation fails or the column family
+/// does not exist.
+pub fn ingest<P>(
+    db: &rocksdb::DBWithThreadMode<rocksdb::MultiThreaded>,
+    cf_name: &str,
+    files: &[P],
+) -> Result<(), error::Error>
+where
+    P: AsRef<Path>,
+{
+    let cf = db
+        .cf_handle(cf_name)
+        .ok_or_else(|| error::Error::ColumnFamily(cf_name.to_owned()))?;
+
+    let ingest_opts = rocksdb::IngestExternalFileOptions::default();
+    db.ingest_external_file_cf_opts(
+        &cf,
+        &ingest_opts,
+        files.iter().map(AsRef::as_ref).collect(),
+    )
+    .map_err(error::Error::Ingest)
+}
+
 #[allow(clippy::pedantic)]
 #[cfg(test)]
 mod test {
@@ -235,6 +685,24 @@ mod test {
         Ok(())
     }
 
+    /// Smoke test for the `tune_options_with_profile` function with the bulk-load and
+    /// point-lookup presets.
+    #[test]
+    fn smoke_test_tune_options_with_profile() -> Result<(), anyhow::Error> {
+        let _tuned = tune_options_with_profile(
+            rocksdb::Options::default(),
+            None,
+            &TuningProfile::bulk_load(),
+        );
+        let _tuned = tune_options_with_profile(
+            rocksdb::Options::default(),
+            None,
+            &TuningProfile::point_lookup(),
+        );
+
+        Ok(())
+    }
+
     /// Smoke test for the `force_compaction` function.
     #[test]
     fn smoke_test_force_compaction() -> Result<(), anyhow::Error> {
@@ -271,6 +739,111 @@ mod test {
         Ok(())
     }
 
+    /// Smoke test for the `list_sst_files` function.
+    #[test]
+    fn smoke_test_list_sst_files() -> Result<(), anyhow::Error> {
+        let path_db = "tests/data/freqs";
+        let db = rocksdb::DB::open_cf_for_read_only(
+            &rocksdb::Options::default(),
+            path_db,
+            ["meta"],
+            true,
+        )?;
+
+        let _sst_files = list_sst_files(&db)?;
+
+        Ok(())
+    }
+
+    /// Smoke test for the `create_checkpoint` function.
+    #[test]
+    fn smoke_test_create_checkpoint() -> Result<(), anyhow::Error> {
+        let temp = TempDir::default();
+        let path_db = temp.join("rocksdb");
+        let path_checkpoint = temp.join("checkpoint");
+
+        let mut options = rocksdb::Options::default();
+        options.create_if_missing(true);
+        options.create_missing_column_families(true);
+        let db = rocksdb::DB::open_cf(&options, &path_db, &["foo", "bar"])?;
+
+        create_checkpoint(&db, &path_checkpoint)?;
+
+        Ok(())
+    }
+
+    /// Smoke test for the `delete_range_fast` function.
+    #[test]
+    fn smoke_test_delete_range_fast() -> Result<(), anyhow::Error> {
+        let temp = TempDir::default();
+        let path_db = temp.join("rocksdb");
+
+        let mut options = rocksdb::Options::default();
+        options.create_if_missing(true);
+        options.create_missing_column_families(true);
+        let cf_names = &["foo", "bar"];
+        let db = rocksdb::DB::open_cf(&options, &path_db, cf_names)?;
+
+        let cf_foo = db.cf_handle("foo").unwrap();
+        db.put_cf(&cf_foo, b"key1", b"value1")?;
+        db.put_cf(&cf_foo, b"key2", b"value2")?;
+        db.flush_cf(&cf_foo)?;
+
+        delete_range_fast(&db, "foo", b"key0", b"key9", true)?;
+
+        assert_eq!(db.get_cf(&cf_foo, b"key1")?, None);
+        assert_eq!(db.get_cf(&cf_foo, b"key2")?, None);
+
+        Ok(())
+    }
+
+    /// Regression test for `delete_range_fast` with a file straddling the range boundary: the
+    /// whole-file fast path cannot drop it, so the result must still be correct without relying
+    /// on `compact_afterwards`.
+    #[test]
+    fn smoke_test_delete_range_fast_partial_overlap() -> Result<(), anyhow::Error> {
+        let temp = TempDir::default();
+        let path_db = temp.join("rocksdb");
+
+        let mut options = rocksdb::Options::default();
+        options.create_if_missing(true);
+        options.create_missing_column_families(true);
+        let cf_names = &["foo", "bar"];
+        let db = rocksdb::DB::open_cf(&options, &path_db, cf_names)?;
+
+        let cf_foo = db.cf_handle("foo").unwrap();
+        // All three keys land in a single SST file spanning "key0".."key2", so it is only
+        // partially contained in the "key1".."key9" range deleted below.
+        db.put_cf(&cf_foo, b"key0", b"value0")?;
+        db.put_cf(&cf_foo, b"key1", b"value1")?;
+        db.put_cf(&cf_foo, b"key2", b"value2")?;
+        db.flush_cf(&cf_foo)?;
+
+        delete_range_fast(&db, "foo", b"key1", b"key9", false)?;
+
+        assert_eq!(db.get_cf(&cf_foo, b"key0")?, Some(b"value0".to_vec()));
+        assert_eq!(db.get_cf(&cf_foo, b"key1")?, None);
+        assert_eq!(db.get_cf(&cf_foo, b"key2")?, None);
+
+        Ok(())
+    }
+
+    /// Smoke test for the `memory_usage` function.
+    #[test]
+    fn smoke_test_memory_usage() -> Result<(), anyhow::Error> {
+        let path_db = "tests/data/freqs";
+        let db = rocksdb::DB::open_cf_for_read_only(
+            &rocksdb::Options::default(),
+            path_db,
+            ["meta"],
+            true,
+        )?;
+
+        let _usage = memory_usage(&db)?;
+
+        Ok(())
+    }
+
     /// Smoke test for the `fetch_meta` function.
     #[test]
     fn smoke_test_fetch_meta() -> Result<(), anyhow::Error> {
@@ -286,4 +859,84 @@ mod test {
 
         Ok(())
     }
+
+    /// Smoke test for the `fetch_meta_typed` function.
+    #[test]
+    fn smoke_test_fetch_meta_typed() -> Result<(), anyhow::Error> {
+        let path_db = "tests/data/freqs";
+        let db = rocksdb::DB::open_cf_for_read_only(
+            &rocksdb::Options::default(),
+            path_db,
+            ["meta"],
+            true,
+        )?;
+
+        let _release: Option<String> = fetch_meta_typed(&db, "gnomad-release")?;
+
+        Ok(())
+    }
+
+    /// Smoke test for the `fetch_all_meta` function.
+    #[test]
+    fn smoke_test_fetch_all_meta() -> Result<(), anyhow::Error> {
+        let path_db = "tests/data/freqs";
+        let db = rocksdb::DB::open_cf_for_read_only(
+            &rocksdb::Options::default(),
+            path_db,
+            ["meta"],
+            true,
+        )?;
+
+        let all_meta = fetch_all_meta(&db)?;
+        assert!(all_meta.contains_key("gnomad-release"));
+
+        Ok(())
+    }
+
+    /// Smoke test for the `put_meta` and `put_meta_batch` functions.
+    #[test]
+    fn smoke_test_put_meta() -> Result<(), anyhow::Error> {
+        let temp = TempDir::default();
+        let path_db = temp.join("rocksdb");
+
+        let mut options = rocksdb::Options::default();
+        options.create_if_missing(true);
+        options.create_missing_column_families(true);
+        let db = rocksdb::DB::open_cf(&options, &path_db, &["meta"])?;
+
+        put_meta(&db, "release", "v1")?;
+        put_meta_batch(&db, &[("key1", "value1"), ("key2", "value2")])?;
+
+        assert_eq!(fetch_meta(&db, "release")?, Some(String::from("v1")));
+        assert_eq!(fetch_meta(&db, "key1")?, Some(String::from("value1")));
+
+        Ok(())
+    }
+
+    /// Smoke test for `SstBulkLoader` and `ingest`.
+    #[test]
+    fn smoke_test_sst_bulk_loader() -> Result<(), anyhow::Error> {
+        let temp = TempDir::default();
+        let path_db = temp.join("rocksdb");
+        let path_sst = temp.join("sst");
+        std::fs::create_dir_all(&path_sst)?;
+
+        let mut options = rocksdb::Options::default();
+        options.create_if_missing(true);
+        options.create_missing_column_families(true);
+        let db = rocksdb::DB::open_cf(&options, &path_db, &["foo"])?;
+
+        let mut loader = SstBulkLoader::new(&options, &path_sst)?;
+        loader.put(b"key1", b"value1")?;
+        loader.put(b"key2", b"value2")?;
+        assert!(loader.put(b"key1", b"value3").is_err());
+        let path_file = loader.finish()?;
+
+        ingest(&db, "foo", &[path_file])?;
+
+        let cf_foo = db.cf_handle("foo").unwrap();
+        assert_eq!(db.get_cf(&cf_foo, b"key1")?, Some(b"value1".to_vec()));
+
+        Ok(())
+    }
 }